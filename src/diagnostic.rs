@@ -5,9 +5,24 @@ pub struct GQLError {
     pub location: Location,
 }
 
-pub fn report_gql_error(error: GQLError) {
+pub fn report_diagnostics(script: &str, errors: &[GQLError]) {
+    for error in errors {
+        report_gql_error(script, error);
+    }
+}
+
+fn report_gql_error(script: &str, error: &GQLError) {
+    let line_content = script.lines().nth(error.location.line - 1).unwrap_or("");
+    let token_length = (error.location.end - error.location.start).max(1);
+
     println!(
         "Error({}:{}) -> {}",
-        error.location.start, error.location.end, error.message
+        error.location.line, error.location.column, error.message
+    );
+    println!("{}", line_content);
+    println!(
+        "{}{}",
+        " ".repeat(error.location.column - 1),
+        "^".repeat(token_length)
     );
 }
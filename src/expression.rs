@@ -1,10 +1,11 @@
 use crate::object::GQLObject;
+use crate::value::Value;
 use regex::Regex;
 
 use crate::transformation::TRANSFORMATIONS;
 
 pub trait Expression {
-    fn evaluate(&self, object: &GQLObject) -> String;
+    fn evaluate(&self, object: &GQLObject) -> Value;
 }
 
 pub struct StringExpression {
@@ -12,8 +13,8 @@ pub struct StringExpression {
 }
 
 impl Expression for StringExpression {
-    fn evaluate(&self, _object: &GQLObject) -> String {
-        return self.value.to_owned();
+    fn evaluate(&self, _object: &GQLObject) -> Value {
+        return Value::Text(self.value.to_owned());
     }
 }
 
@@ -22,8 +23,8 @@ pub struct SymbolExpression {
 }
 
 impl Expression for SymbolExpression {
-    fn evaluate(&self, object: &GQLObject) -> String {
-        return object.attributes.get(&self.value).unwrap().to_string();
+    fn evaluate(&self, object: &GQLObject) -> Value {
+        return object.attributes.get(&self.value).unwrap().clone();
     }
 }
 
@@ -32,9 +33,9 @@ pub struct NotExpression {
 }
 
 impl Expression for NotExpression {
-    fn evaluate(&self, object: &GQLObject) -> String {
+    fn evaluate(&self, object: &GQLObject) -> Value {
         let value = self.right.evaluate(object);
-        return (!value.eq("true")).to_string();
+        return Value::Boolean(!value.as_bool());
     }
 }
 
@@ -55,19 +56,28 @@ pub struct ComparisonExpression {
 }
 
 impl Expression for ComparisonExpression {
-    fn evaluate(&self, object: &GQLObject) -> String {
+    fn evaluate(&self, object: &GQLObject) -> Value {
         let value = self.left.evaluate(object);
         let expected = self.right.evaluate(object);
-        let result = value.cmp(&expected);
-        return match self.operator {
+
+        // Let a runtime error (e.g. a division by zero upstream) surface as
+        // itself instead of being coerced into a string/number comparison.
+        if value.is_error() {
+            return value;
+        }
+        if expected.is_error() {
+            return expected;
+        }
+
+        let result = value.compare(&expected);
+        return Value::Boolean(match self.operator {
             ComparisonOperator::Greater => result.is_gt(),
             ComparisonOperator::GreaterEqual => result.is_ge(),
             ComparisonOperator::Less => result.is_lt(),
             ComparisonOperator::LessEqual => result.is_le(),
             ComparisonOperator::Equal => result.is_eq(),
             ComparisonOperator::NotEqual => !result.is_eq(),
-        }
-        .to_string();
+        });
     }
 }
 
@@ -86,23 +96,22 @@ pub struct CheckExpression {
 }
 
 impl Expression for CheckExpression {
-    fn evaluate(&self, object: &GQLObject) -> String {
-        let value = self.left.evaluate(object);
-        let expected = self.right.evaluate(object);
+    fn evaluate(&self, object: &GQLObject) -> Value {
+        let value = self.left.evaluate(object).to_string();
+        let expected = self.right.evaluate(object).to_string();
 
-        return match self.operator {
+        return Value::Boolean(match self.operator {
             CheckOperator::Contains => value.contains(&expected),
             CheckOperator::StartsWith => value.starts_with(&expected),
             CheckOperator::EndsWith => value.ends_with(&expected),
             CheckOperator::Matches => {
                 let regex = Regex::new(&expected);
                 if regex.is_err() {
-                    return "false".to_owned();
+                    return Value::Boolean(false);
                 }
                 regex.unwrap().is_match(&value)
             }
-        }
-        .to_string();
+        });
     }
 }
 
@@ -120,25 +129,73 @@ pub struct LogicalExpression {
 }
 
 impl Expression for LogicalExpression {
-    fn evaluate(&self, object: &GQLObject) -> String {
-        let lhs = self.left.evaluate(object).eq("true");
+    fn evaluate(&self, object: &GQLObject) -> Value {
+        let lhs = self.left.evaluate(object).as_bool();
 
         if self.operator == LogicalOperator::And && !lhs {
-            return "false".to_owned();
+            return Value::Boolean(false);
         }
 
         if self.operator == LogicalOperator::Or && lhs {
-            return "true".to_owned();
+            return Value::Boolean(true);
         }
 
-        let rhs = self.right.evaluate(object).eq("true");
+        let rhs = self.right.evaluate(object).as_bool();
 
-        return match self.operator {
+        return Value::Boolean(match self.operator {
             LogicalOperator::And => lhs && rhs,
             LogicalOperator::Or => lhs || rhs,
             LogicalOperator::Xor => lhs ^ rhs,
+        });
+    }
+}
+
+#[derive(PartialEq)]
+pub enum ArithmeticOperator {
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+}
+
+pub struct BinaryExpression {
+    pub left: Box<dyn Expression>,
+    pub operator: ArithmeticOperator,
+    pub right: Box<dyn Expression>,
+}
+
+impl Expression for BinaryExpression {
+    fn evaluate(&self, object: &GQLObject) -> Value {
+        let left = self.left.evaluate(object);
+        let right = self.right.evaluate(object);
+
+        let (left_number, right_number) = match (left.as_f64(), right.as_f64()) {
+            (Some(left_number), Some(right_number)) => (left_number, right_number),
+            _ => return Value::Error("arithmetic operators require numeric operands".to_owned()),
+        };
+
+        let is_division = self.operator == ArithmeticOperator::Slash
+            || self.operator == ArithmeticOperator::Percent;
+
+        if is_division && right_number == 0.0 {
+            return Value::Error("division by zero".to_owned());
+        }
+
+        let result = match self.operator {
+            ArithmeticOperator::Plus => left_number + right_number,
+            ArithmeticOperator::Minus => left_number - right_number,
+            ArithmeticOperator::Star => left_number * right_number,
+            ArithmeticOperator::Slash => left_number / right_number,
+            ArithmeticOperator::Percent => left_number % right_number,
+        };
+
+        let both_integers = matches!(left, Value::Integer(_)) && matches!(right, Value::Integer(_));
+        if !is_division && both_integers {
+            return Value::Integer(result as i64);
         }
-        .to_string();
+
+        return Value::Float(result);
     }
 }
 
@@ -148,9 +205,216 @@ pub struct CallExpression {
 }
 
 impl Expression for CallExpression {
-    fn evaluate(&self, object: &GQLObject) -> String {
-        let lhs = self.left.evaluate(object);
+    fn evaluate(&self, object: &GQLObject) -> Value {
+        let lhs = self.left.evaluate(object).to_string();
         let transformation = TRANSFORMATIONS.get(self.function_name.as_str()).unwrap();
-        return transformation(lhs);
+        return Value::Text(transformation(lhs));
+    }
+}
+
+/// Aggregates fold an entire group of rows down to a single `Value`, unlike
+/// `CallExpression`, which runs a `TRANSFORMATIONS` function per row.
+pub enum AggregateFunction {
+    Count,
+    Max(String),
+    Min(String),
+    Sum(String),
+    Avg(String),
+}
+
+impl AggregateFunction {
+    pub fn apply(&self, group: &[GQLObject]) -> Value {
+        return match self {
+            AggregateFunction::Count => Value::Integer(group.len() as i64),
+            AggregateFunction::Max(field_name) => {
+                Value::Float(fold_numeric_field(group, field_name, f64::MIN, f64::max))
+            }
+            AggregateFunction::Min(field_name) => {
+                Value::Float(fold_numeric_field(group, field_name, f64::MAX, f64::min))
+            }
+            AggregateFunction::Sum(field_name) => {
+                Value::Float(fold_numeric_field(group, field_name, 0.0, |acc, x| acc + x))
+            }
+            AggregateFunction::Avg(field_name) => {
+                let values = numeric_field_values(group, field_name);
+                if values.is_empty() {
+                    Value::Float(0.0)
+                } else {
+                    Value::Float(values.iter().sum::<f64>() / values.len() as f64)
+                }
+            }
+        };
+    }
+}
+
+fn numeric_field_values(group: &[GQLObject], field_name: &str) -> Vec<f64> {
+    return group
+        .iter()
+        .filter_map(|object| object.attributes.get(field_name))
+        .filter_map(Value::as_f64)
+        .collect();
+}
+
+fn fold_numeric_field(
+    group: &[GQLObject],
+    field_name: &str,
+    initial: f64,
+    fold: fn(f64, f64) -> f64,
+) -> f64 {
+    return numeric_field_values(group, field_name)
+        .into_iter()
+        .fold(initial, fold);
+}
+
+/// What a parsed `name(argument)` call resolves to: a set-level aggregate
+/// (`count`, `max`, `min`, `sum`, `avg`), evaluated once over a group of rows,
+/// or a per-row `CallExpression` resolved against `TRANSFORMATIONS` otherwise.
+pub enum FunctionCallResolution {
+    Aggregate(AggregateFunction),
+    Row(CallExpression),
+}
+
+pub fn resolve_function_call(
+    function_name: &str,
+    argument: String,
+    left: Box<dyn Expression>,
+) -> FunctionCallResolution {
+    return match function_name {
+        "count" => FunctionCallResolution::Aggregate(AggregateFunction::Count),
+        "max" => FunctionCallResolution::Aggregate(AggregateFunction::Max(argument)),
+        "min" => FunctionCallResolution::Aggregate(AggregateFunction::Min(argument)),
+        "sum" => FunctionCallResolution::Aggregate(AggregateFunction::Sum(argument)),
+        "avg" => FunctionCallResolution::Aggregate(AggregateFunction::Avg(argument)),
+        _ => FunctionCallResolution::Row(CallExpression {
+            left,
+            function_name: function_name.to_owned(),
+        }),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn empty_object() -> GQLObject {
+        GQLObject {
+            attributes: HashMap::new(),
+        }
+    }
+
+    // StringExpression always evaluates to Value::Text, so this helper builds
+    // an expression that returns a fixed typed Value directly for the tests below.
+    struct FixedExpression {
+        value: Value,
+    }
+
+    impl Expression for FixedExpression {
+        fn evaluate(&self, _object: &GQLObject) -> Value {
+            self.value.clone()
+        }
+    }
+
+    fn fixed_binary(left: Value, operator: ArithmeticOperator, right: Value) -> BinaryExpression {
+        BinaryExpression {
+            left: Box::new(FixedExpression { value: left }),
+            operator,
+            right: Box::new(FixedExpression { value: right }),
+        }
+    }
+
+    #[test]
+    fn adds_two_integers_and_stays_integer() {
+        let expression = fixed_binary(
+            Value::Integer(2),
+            ArithmeticOperator::Plus,
+            Value::Integer(3),
+        );
+        match expression.evaluate(&empty_object()) {
+            Value::Integer(sum) => assert_eq!(sum, 5),
+            other => panic!("expected Value::Integer, got {} instead", other),
+        }
+    }
+
+    #[test]
+    fn mixing_integer_and_float_produces_float() {
+        let expression = fixed_binary(
+            Value::Integer(2),
+            ArithmeticOperator::Plus,
+            Value::Float(0.5),
+        );
+        match expression.evaluate(&empty_object()) {
+            Value::Float(sum) => assert_eq!(sum, 2.5),
+            other => panic!("expected Value::Float, got {} instead", other),
+        }
+    }
+
+    #[test]
+    fn division_by_zero_yields_an_error_value_not_text() {
+        let expression = fixed_binary(
+            Value::Integer(10),
+            ArithmeticOperator::Slash,
+            Value::Integer(0),
+        );
+        let result = expression.evaluate(&empty_object());
+        assert!(result.is_error());
+    }
+
+    #[test]
+    fn non_numeric_operand_yields_an_error_value_not_text() {
+        let expression = fixed_binary(
+            Value::Integer(10),
+            ArithmeticOperator::Plus,
+            Value::Text("not a number".to_owned()),
+        );
+        let result = expression.evaluate(&empty_object());
+        assert!(result.is_error());
+    }
+
+    #[test]
+    fn comparison_propagates_an_errored_operand_instead_of_comparing_it() {
+        let comparison = ComparisonExpression {
+            left: Box::new(fixed_binary(
+                Value::Integer(10),
+                ArithmeticOperator::Slash,
+                Value::Integer(0),
+            )),
+            operator: ComparisonOperator::Greater,
+            right: Box::new(FixedExpression {
+                value: Value::Integer(5),
+            }),
+        };
+
+        let result = comparison.evaluate(&empty_object());
+        assert!(result.is_error());
+    }
+
+    #[test]
+    fn resolves_known_aggregate_names_instead_of_a_row_call() {
+        let resolution = resolve_function_call(
+            "count",
+            "*".to_owned(),
+            Box::new(StringExpression {
+                value: String::new(),
+            }),
+        );
+
+        assert!(matches!(
+            resolution,
+            FunctionCallResolution::Aggregate(AggregateFunction::Count)
+        ));
+    }
+
+    #[test]
+    fn falls_back_to_a_row_call_for_unknown_function_names() {
+        let resolution = resolve_function_call(
+            "lower",
+            String::new(),
+            Box::new(StringExpression {
+                value: "HELLO".to_owned(),
+            }),
+        );
+
+        assert!(matches!(resolution, FunctionCallResolution::Row(_)));
     }
 }
@@ -1,8 +1,10 @@
 use std::cmp;
+use std::collections::HashMap;
 
 use crate::engine_function::select_gql_objects;
-use crate::expression::Expression;
+use crate::expression::{AggregateFunction, Expression};
 use crate::object::GQLObject;
+use crate::value::Value;
 
 pub trait Statement {
     fn execute(&self, repo: &git2::Repository, objects: &mut Vec<GQLObject>);
@@ -11,15 +13,25 @@ pub trait Statement {
 pub struct SelectStatement {
     pub table_name: String,
     pub fields: Vec<String>,
+    /// Aggregate columns requested directly on the select (e.g. `select
+    /// count(*)` with no `group by`); each collapses the whole table into one
+    /// computed column on a single result row.
+    pub aggregations: Vec<(String, AggregateFunction)>,
 }
 
 impl Statement for SelectStatement {
     fn execute(&self, repo: &git2::Repository, objects: &mut Vec<GQLObject>) {
         let elements =
             select_gql_objects(repo, self.table_name.to_string(), self.fields.to_owned());
-        for element in elements {
-            objects.push(element);
+
+        if self.aggregations.is_empty() {
+            for element in elements {
+                objects.push(element);
+            }
+            return;
         }
+
+        objects.push(aggregate_row(&[], &elements, &self.aggregations));
     }
 }
 
@@ -31,7 +43,7 @@ impl Statement for WhereStatement {
     fn execute(&self, _repo: &git2::Repository, objects: &mut Vec<GQLObject>) {
         let result: Vec<GQLObject> = objects
             .iter()
-            .filter(|&object| self.condition.evaluate(object).eq("true"))
+            .filter(|&object| self.condition.evaluate(object).as_bool())
             .cloned()
             .collect();
 
@@ -76,13 +88,192 @@ impl Statement for OrderByStatement {
         }
 
         if objects[0].attributes.contains_key(&self.field_name) {
-            objects.sort_by_key(|object| {
-                object
-                    .attributes
-                    .get(&self.field_name.to_string())
-                    .unwrap()
-                    .to_string()
+            objects.sort_by(|first, second| {
+                let first_value = first.attributes.get(&self.field_name).unwrap();
+                let second_value = second.attributes.get(&self.field_name).unwrap();
+                first_value.compare(second_value)
+            });
+        }
+    }
+}
+
+/// Collapses one group's objects into a single row carrying the group-by
+/// fields (`key`, parallel to `field_names`) plus the computed aggregate
+/// columns. Shared by `SelectStatement`'s bare aggregates (no `field_names`)
+/// and `GroupByStatement`'s per-group rows.
+fn aggregate_row(
+    field_names: &[String],
+    group_objects: &[GQLObject],
+    aggregations: &[(String, AggregateFunction)],
+) -> GQLObject {
+    let mut attributes: HashMap<String, Value> = HashMap::new();
+
+    for field_name in field_names {
+        let value = group_objects[0].attributes.get(field_name).unwrap().clone();
+        attributes.insert(field_name.to_string(), value);
+    }
+
+    for (output_name, aggregate) in aggregations {
+        attributes.insert(output_name.to_string(), aggregate.apply(group_objects));
+    }
+
+    return GQLObject { attributes };
+}
+
+/// Groups objects by one or more field values and collapses each group into a
+/// single row carrying the group-by fields plus the computed aggregate columns.
+pub struct GroupByStatement {
+    pub field_names: Vec<String>,
+    pub aggregations: Vec<(String, AggregateFunction)>,
+}
+
+impl Statement for GroupByStatement {
+    fn execute(&self, _repo: &git2::Repository, objects: &mut Vec<GQLObject>) {
+        let mut groups: Vec<Vec<GQLObject>> = Vec::new();
+
+        for object in objects.iter() {
+            let key: Vec<Value> = self
+                .field_names
+                .iter()
+                .map(|field_name| object.attributes.get(field_name).unwrap().clone())
+                .collect();
+
+            let existing_group = groups.iter_mut().find(|group_objects| {
+                let group_key: Vec<Value> = self
+                    .field_names
+                    .iter()
+                    .map(|field_name| group_objects[0].attributes.get(field_name).unwrap().clone())
+                    .collect();
+                group_key == key
             });
+
+            match existing_group {
+                Some(group_objects) => group_objects.push(object.clone()),
+                None => groups.push(vec![object.clone()]),
+            }
         }
+
+        // A bare aggregate with no `group by` fields still reports one row
+        // (e.g. `count(*)` is 0) even when the input set is empty.
+        if groups.is_empty() && self.field_names.is_empty() {
+            groups.push(Vec::new());
+        }
+
+        let result: Vec<GQLObject> = groups
+            .iter()
+            .map(|group_objects| {
+                aggregate_row(&self.field_names, group_objects, &self.aggregations)
+            })
+            .collect();
+
+        objects.clear();
+        objects.extend(result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(attributes: Vec<(&str, Value)>) -> GQLObject {
+        GQLObject {
+            attributes: attributes
+                .into_iter()
+                .map(|(name, value)| (name.to_owned(), value))
+                .collect(),
+        }
+    }
+
+    // `GroupByStatement::execute` ignores its `repo` argument, so any open
+    // repository works here; the crate root is always one.
+    fn this_repo() -> git2::Repository {
+        git2::Repository::open(".").unwrap()
+    }
+
+    #[test]
+    fn bare_count_over_an_empty_set_still_yields_one_zero_row() {
+        let statement = GroupByStatement {
+            field_names: vec![],
+            aggregations: vec![("count".to_owned(), AggregateFunction::Count)],
+        };
+
+        let mut objects: Vec<GQLObject> = Vec::new();
+        statement.execute(&this_repo(), &mut objects);
+
+        assert_eq!(objects.len(), 1);
+        assert_eq!(objects[0].attributes.get("count"), Some(&Value::Integer(0)));
+    }
+
+    #[test]
+    fn grouping_by_a_missing_field_over_an_empty_set_yields_no_rows() {
+        let statement = GroupByStatement {
+            field_names: vec!["author".to_owned()],
+            aggregations: vec![("count".to_owned(), AggregateFunction::Count)],
+        };
+
+        let mut objects: Vec<GQLObject> = Vec::new();
+        statement.execute(&this_repo(), &mut objects);
+
+        assert!(objects.is_empty());
+    }
+
+    #[test]
+    fn groups_rows_by_exact_value_not_by_display_text() {
+        let statement = GroupByStatement {
+            field_names: vec!["id".to_owned()],
+            aggregations: vec![("count".to_owned(), AggregateFunction::Count)],
+        };
+
+        let mut objects = vec![
+            object(vec![("id", Value::Integer(5))]),
+            object(vec![("id", Value::Text("5".to_owned()))]),
+        ];
+
+        statement.execute(&this_repo(), &mut objects);
+
+        assert_eq!(objects.len(), 2);
+    }
+
+    #[test]
+    fn groups_rows_that_share_the_same_field_value() {
+        let statement = GroupByStatement {
+            field_names: vec!["author".to_owned()],
+            aggregations: vec![
+                ("count".to_owned(), AggregateFunction::Count),
+                (
+                    "total_insertions".to_owned(),
+                    AggregateFunction::Sum("insertions".to_owned()),
+                ),
+            ],
+        };
+
+        let mut objects = vec![
+            object(vec![
+                ("author", Value::Text("amr".to_owned())),
+                ("insertions", Value::Integer(10)),
+            ]),
+            object(vec![
+                ("author", Value::Text("amr".to_owned())),
+                ("insertions", Value::Integer(5)),
+            ]),
+            object(vec![
+                ("author", Value::Text("mohamed".to_owned())),
+                ("insertions", Value::Integer(3)),
+            ]),
+        ];
+
+        statement.execute(&this_repo(), &mut objects);
+
+        assert_eq!(objects.len(), 2);
+
+        let amr_row = objects
+            .iter()
+            .find(|object| object.attributes.get("author") == Some(&Value::Text("amr".to_owned())))
+            .unwrap();
+        assert_eq!(amr_row.attributes.get("count"), Some(&Value::Integer(2)));
+        assert_eq!(
+            amr_row.attributes.get("total_insertions"),
+            Some(&Value::Float(15.0))
+        );
     }
 }
@@ -9,14 +9,31 @@ pub enum TokenKind {
     By,
 
     Equal,
+    Greater,
+    GreaterEqual,
+    Less,
+    LessEqual,
+    NotEqual,
+
     Or,
     And,
+    Not,
+    Xor,
+
+    Contains,
+    StartsWith,
+    EndsWith,
+    Matches,
 
     Symbol,
     Number,
     String,
 
+    Plus,
+    Minus,
     Star,
+    Slash,
+    Percent,
 
     Comma,
 }
@@ -25,6 +42,8 @@ pub enum TokenKind {
 pub struct Location {
     pub start: usize,
     pub end: usize,
+    pub line: usize,
+    pub column: usize,
 }
 
 pub struct Token {
@@ -35,23 +54,32 @@ pub struct Token {
 
 use crate::diagnostic::GQLError;
 
-pub fn tokenize(script: String) -> Result<Vec<Token>, GQLError> {
+pub fn tokenize(script: String) -> Result<Vec<Token>, Vec<GQLError>> {
     let mut tokens: Vec<Token> = Vec::new();
+    let mut errors: Vec<GQLError> = Vec::new();
 
     let mut position = 0;
     let mut column_start = 0;
 
+    let mut line = 1;
+    let mut line_start = 0;
+
     let characters: Vec<char> = script.chars().collect();
     let len = characters.len();
 
     while position < len {
         column_start = position;
 
+        let token_line = line;
+        let token_column = column_start - line_start + 1;
+
         let char = characters[position];
 
         // Tokenize Symbol
         if char.is_alphabetic() {
-            while position < len && characters[position].is_alphabetic() {
+            while position < len
+                && (characters[position].is_alphabetic() || characters[position] == '_')
+            {
                 position += 1;
             }
 
@@ -59,6 +87,8 @@ pub fn tokenize(script: String) -> Result<Vec<Token>, GQLError> {
             let location = Location {
                 start: column_start,
                 end: position,
+                line: token_line,
+                column: token_column,
             };
 
             let token = Token {
@@ -81,6 +111,8 @@ pub fn tokenize(script: String) -> Result<Vec<Token>, GQLError> {
             let location = Location {
                 start: column_start,
                 end: position,
+                line: token_line,
+                column: token_column,
             };
 
             let token = Token {
@@ -95,22 +127,84 @@ pub fn tokenize(script: String) -> Result<Vec<Token>, GQLError> {
 
         if char == '"' {
             position += 1;
-            while position < len && characters[position] != '"' {
+            let mut literal = String::new();
+            let mut terminated = false;
+            let mut invalid_escape_location: Option<Location> = None;
+
+            while position < len {
+                if characters[position] == '"' {
+                    terminated = true;
+                    position += 1;
+                    break;
+                }
+
+                if characters[position] == '\n' {
+                    line += 1;
+                    line_start = position + 1;
+                }
+
+                if characters[position] == '\\' && position + 1 < len {
+                    let escaped = match characters[position + 1] {
+                        '"' => Some('"'),
+                        '\\' => Some('\\'),
+                        'n' => Some('\n'),
+                        't' => Some('\t'),
+                        'r' => Some('\r'),
+                        _ => None,
+                    };
+
+                    match escaped {
+                        Some(character) => {
+                            literal.push(character);
+                            position += 2;
+                            continue;
+                        }
+                        None => {
+                            if invalid_escape_location.is_none() {
+                                invalid_escape_location = Some(Location {
+                                    start: position,
+                                    end: position + 2,
+                                    line,
+                                    column: position - line_start + 1,
+                                });
+                            }
+                            position += 2;
+                            continue;
+                        }
+                    }
+                }
+
+                literal.push(characters[position]);
                 position += 1;
             }
-            position += 1;
-
-            let literal = &script[column_start + 1..position - 1];
 
             let location = Location {
                 start: column_start,
                 end: position,
+                line: token_line,
+                column: token_column,
             };
 
+            if !terminated {
+                errors.push(GQLError {
+                    message: "Unterminated string literal".to_owned(),
+                    location: location,
+                });
+                continue;
+            }
+
+            if let Some(escape_location) = invalid_escape_location {
+                errors.push(GQLError {
+                    message: "Unknown escape sequence in string literal".to_owned(),
+                    location: escape_location,
+                });
+                continue;
+            }
+
             let token = Token {
                 location: location,
                 kind: TokenKind::String,
-                literal: literal.to_string(),
+                literal: literal,
             };
 
             tokens.push(token);
@@ -122,6 +216,8 @@ pub fn tokenize(script: String) -> Result<Vec<Token>, GQLError> {
             let location = Location {
                 start: column_start,
                 end: position,
+                line: token_line,
+                column: token_column,
             };
 
             let token = Token {
@@ -135,11 +231,93 @@ pub fn tokenize(script: String) -> Result<Vec<Token>, GQLError> {
             continue;
         }
 
+        // Plus
+        if char == '+' {
+            let location = Location {
+                start: column_start,
+                end: position,
+                line: token_line,
+                column: token_column,
+            };
+
+            let token = Token {
+                location: location,
+                kind: TokenKind::Plus,
+                literal: "+".to_owned(),
+            };
+
+            tokens.push(token);
+            position += 1;
+            continue;
+        }
+
+        // Minus
+        if char == '-' {
+            let location = Location {
+                start: column_start,
+                end: position,
+                line: token_line,
+                column: token_column,
+            };
+
+            let token = Token {
+                location: location,
+                kind: TokenKind::Minus,
+                literal: "-".to_owned(),
+            };
+
+            tokens.push(token);
+            position += 1;
+            continue;
+        }
+
+        // Slash
+        if char == '/' {
+            let location = Location {
+                start: column_start,
+                end: position,
+                line: token_line,
+                column: token_column,
+            };
+
+            let token = Token {
+                location: location,
+                kind: TokenKind::Slash,
+                literal: "/".to_owned(),
+            };
+
+            tokens.push(token);
+            position += 1;
+            continue;
+        }
+
+        // Percent
+        if char == '%' {
+            let location = Location {
+                start: column_start,
+                end: position,
+                line: token_line,
+                column: token_column,
+            };
+
+            let token = Token {
+                location: location,
+                kind: TokenKind::Percent,
+                literal: "%".to_owned(),
+            };
+
+            tokens.push(token);
+            position += 1;
+            continue;
+        }
+
         // Or
         if char == '|' {
             let location = Location {
                 start: column_start,
                 end: position,
+                line: token_line,
+                column: token_column,
             };
 
             let token = Token {
@@ -158,6 +336,8 @@ pub fn tokenize(script: String) -> Result<Vec<Token>, GQLError> {
             let location = Location {
                 start: column_start,
                 end: position,
+                line: token_line,
+                column: token_column,
             };
 
             let token = Token {
@@ -176,6 +356,8 @@ pub fn tokenize(script: String) -> Result<Vec<Token>, GQLError> {
             let location = Location {
                 start: column_start,
                 end: position,
+                line: token_line,
+                column: token_column,
             };
 
             let token = Token {
@@ -194,6 +376,8 @@ pub fn tokenize(script: String) -> Result<Vec<Token>, GQLError> {
             let location = Location {
                 start: column_start,
                 end: position,
+                line: token_line,
+                column: token_column,
             };
 
             let token = Token {
@@ -207,19 +391,125 @@ pub fn tokenize(script: String) -> Result<Vec<Token>, GQLError> {
             continue;
         }
 
+        // Greater or GreaterEqual
+        if char == '>' {
+            position += 1;
+
+            let is_greater_equal = position < len && characters[position] == '=';
+            if is_greater_equal {
+                position += 1;
+            }
+
+            let location = Location {
+                start: column_start,
+                end: position,
+                line: token_line,
+                column: token_column,
+            };
+
+            let token = Token {
+                location: location,
+                kind: if is_greater_equal {
+                    TokenKind::GreaterEqual
+                } else {
+                    TokenKind::Greater
+                },
+                literal: script[column_start..position].to_owned(),
+            };
+
+            tokens.push(token);
+            continue;
+        }
+
+        // Less, LessEqual or NotEqual (<>)
+        if char == '<' {
+            position += 1;
+
+            let kind = if position < len && characters[position] == '=' {
+                position += 1;
+                TokenKind::LessEqual
+            } else if position < len && characters[position] == '>' {
+                position += 1;
+                TokenKind::NotEqual
+            } else {
+                TokenKind::Less
+            };
+
+            let location = Location {
+                start: column_start,
+                end: position,
+                line: token_line,
+                column: token_column,
+            };
+
+            let token = Token {
+                location: location,
+                kind: kind,
+                literal: script[column_start..position].to_owned(),
+            };
+
+            tokens.push(token);
+            continue;
+        }
+
+        // NotEqual (!=)
+        if char == '!' && position + 1 < len && characters[position + 1] == '=' {
+            position += 2;
+
+            let location = Location {
+                start: column_start,
+                end: position,
+                line: token_line,
+                column: token_column,
+            };
+
+            let token = Token {
+                location: location,
+                kind: TokenKind::NotEqual,
+                literal: "!=".to_owned(),
+            };
+
+            tokens.push(token);
+            continue;
+        }
+
+        // New line, bump the line counter and reset the column origin
+        if char == '\n' {
+            line += 1;
+            position += 1;
+            line_start = position;
+            continue;
+        }
+
         // Characters to ignoring
-        if char == ' ' || char == '\n' || char == '\t' {
+        if char == ' ' || char == '\t' {
             position += 1;
             continue;
         }
 
-        return Err(GQLError {
+        errors.push(GQLError {
             message: "Un expected character".to_owned(),
             location: Location {
                 start: column_start,
-                end: position,
+                end: position + 1,
+                line: token_line,
+                column: token_column,
             },
         });
+
+        // Skip to the next whitespace boundary and keep scanning instead of
+        // bailing out on the first invalid character.
+        while position < len
+            && characters[position] != ' '
+            && characters[position] != '\t'
+            && characters[position] != '\n'
+        {
+            position += 1;
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
     }
 
     return Ok(tokens);
@@ -234,6 +524,69 @@ fn resolve_symbol_kind(literal: String) -> TokenKind {
         "offset" => TokenKind::Offset,
         "order" => TokenKind::Order,
         "by" => TokenKind::By,
+        "and" => TokenKind::And,
+        "or" => TokenKind::Or,
+        "not" => TokenKind::Not,
+        "xor" => TokenKind::Xor,
+        "contains" => TokenKind::Contains,
+        "starts_with" | "startswith" => TokenKind::StartsWith,
+        "ends_with" => TokenKind::EndsWith,
+        "matches" => TokenKind::Matches,
         _ => TokenKind::Symbol,
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenizes_the_two_character_comparison_operators() {
+        let tokens = tokenize(">= <= <> !=".to_owned()).unwrap();
+
+        let kinds: Vec<&TokenKind> = tokens.iter().map(|token| &token.kind).collect();
+        assert!(matches!(kinds[0], TokenKind::GreaterEqual));
+        assert!(matches!(kinds[1], TokenKind::LessEqual));
+        assert!(matches!(kinds[2], TokenKind::NotEqual));
+        assert!(matches!(kinds[3], TokenKind::NotEqual));
+    }
+
+    #[test]
+    fn reports_line_and_column_after_embedded_newlines() {
+        let tokens = tokenize("select\nfrom x".to_owned()).unwrap();
+
+        let symbol = tokens
+            .iter()
+            .find(|token| matches!(token.kind, TokenKind::Symbol))
+            .unwrap();
+        assert_eq!(symbol.literal, "x");
+        assert_eq!(symbol.location.line, 2);
+        assert_eq!(symbol.location.column, 6);
+    }
+
+    #[test]
+    fn unescapes_known_sequences_in_string_literals() {
+        let tokens = tokenize(r#""a\"b\nc""#.to_owned()).unwrap();
+
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].literal, "a\"b\nc");
+    }
+
+    #[test]
+    fn reports_only_the_unknown_escape_not_the_whole_string() {
+        let errors = tokenize(r#""ab\xcd""#.to_owned()).unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].location.start, 3);
+        assert_eq!(errors[0].location.end, 5);
+    }
+
+    #[test]
+    fn recovers_after_an_invalid_character_and_reports_a_later_error_too() {
+        let errors = tokenize("select ~ from # x".to_owned()).unwrap_err();
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].location.column, 8);
+        assert_eq!(errors[1].location.column, 15);
+    }
+}
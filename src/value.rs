@@ -0,0 +1,93 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Value {
+    Integer(i64),
+    Float(f64),
+    Text(String),
+    Boolean(bool),
+    /// A runtime evaluation failure (e.g. division by zero). Kept distinct from
+    /// `Text` so callers can tell a diagnostic apart from real row data instead
+    /// of silently comparing/filtering/displaying it as a string.
+    Error(String),
+}
+
+impl Value {
+    pub fn as_bool(&self) -> bool {
+        match self {
+            Value::Boolean(boolean) => *boolean,
+            _ => false,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Integer(integer) => Some(*integer as f64),
+            Value::Float(float) => Some(*float),
+            _ => None,
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        matches!(self, Value::Error(_))
+    }
+
+    pub fn compare(&self, other: &Value) -> Ordering {
+        if let (Some(left), Some(right)) = (self.as_f64(), other.as_f64()) {
+            return left.partial_cmp(&right).unwrap_or(Ordering::Equal);
+        }
+
+        self.to_string().cmp(&other.to_string())
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Integer(integer) => write!(f, "{}", integer),
+            Value::Float(float) => write!(f, "{}", float),
+            Value::Text(text) => write!(f, "{}", text),
+            Value::Boolean(boolean) => write!(f, "{}", boolean),
+            Value::Error(message) => write!(f, "Error: {}", message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_integers_numerically_not_lexicographically() {
+        let nine = Value::Integer(9);
+        let ten = Value::Integer(10);
+
+        assert_eq!(ten.compare(&nine), Ordering::Greater);
+        assert_eq!(nine.compare(&ten), Ordering::Less);
+    }
+
+    #[test]
+    fn compares_mixed_integer_and_float_numerically() {
+        let integer = Value::Integer(2);
+        let float = Value::Float(2.5);
+
+        assert_eq!(integer.compare(&float), Ordering::Less);
+    }
+
+    #[test]
+    fn falls_back_to_string_ordering_for_text() {
+        let a = Value::Text("a".to_owned());
+        let b = Value::Text("b".to_owned());
+
+        assert_eq!(a.compare(&b), Ordering::Less);
+    }
+
+    #[test]
+    fn compares_text_that_looks_numeric_lexicographically() {
+        let nine = Value::Text("9".to_owned());
+        let ten = Value::Text("10".to_owned());
+
+        assert_eq!(ten.compare(&nine), Ordering::Less);
+    }
+}